@@ -0,0 +1,21 @@
+import {PhotoCamera} from "@mui/icons-material";
+import {Button} from "@mui/material";
+import {useTheme} from "@mui/material/styles";
+import {screenshotAtom} from "lib/atoms/atoms";
+import {useUpdateAtom} from "jotai/utils";
+
+export const ScreenshotButton = () => {
+    const setScreenshot = useUpdateAtom(screenshotAtom);
+
+    const theme = useTheme();
+    return (
+        <Button
+            title='Save screenshot (PNG)'
+            onClick={() => setScreenshot(true)}
+            sx={{color: theme.palette.primary.light}}>
+                <PhotoCamera />
+        </Button>
+    );
+}
+
+export default ScreenshotButton;
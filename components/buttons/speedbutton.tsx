@@ -0,0 +1,26 @@
+import {Button} from "@mui/material";
+import {useTheme} from "@mui/material/styles";
+import {useAtom, useAtomValue} from "jotai";
+import {fixedTimestepAtom, speedAtom} from "lib/atoms/atoms";
+
+const SPEEDS = [0.25, 0.5, 1, 2, 4];
+
+export const SpeedButton = () => {
+    const [speed, setSpeed] = useAtom(speedAtom);
+    const fixedTimestep = useAtomValue(fixedTimestepAtom);
+    const theme = useTheme();
+
+    // fixed timestep derives time from the frame count, so speed doesn't apply
+    const effectiveSpeed = fixedTimestep ? 1 : speed;
+    return (
+        <Button
+            title='Playback speed (click to cycle)'
+            onClick={() => setSpeed(SPEEDS[(SPEEDS.indexOf(speed) + 1) % SPEEDS.length])}
+            disabled={fixedTimestep}
+            sx={effectiveSpeed !== 1 ? {color: theme.palette.primary.contrastText} : {color: theme.palette.primary.light}}>
+                {effectiveSpeed}x
+        </Button>
+    );
+}
+
+export default SpeedButton;
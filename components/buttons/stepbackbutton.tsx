@@ -0,0 +1,26 @@
+import SkipPreviousIcon from "@mui/icons-material/SkipPrevious";
+import {Button} from "@mui/material";
+import {useTheme} from "@mui/material/styles";
+import {useAtomValue} from "jotai";
+import {playAtom, seekAtom, timerAtom} from "lib/atoms/atoms";
+import {useUpdateAtom} from "jotai/utils";
+
+export const StepBackButton = () => {
+    const play = useAtomValue(playAtom);
+    const timer = useAtomValue(timerAtom);
+    const setSeek = useUpdateAtom(seekAtom);
+
+    // there's no way to run a shader backwards, so this replays from the start
+    const theme = useTheme();
+    return (
+        <Button
+            title='Step back one frame while paused <Ctrl + Alt + Left>'
+            onClick={() => setSeek(Math.max(0, timer - 1 / 60))}
+            disabled={play || timer <= 0}
+            sx={{color: theme.palette.primary.light}}>
+                <SkipPreviousIcon />
+        </Button>
+    );
+}
+
+export default StepBackButton;
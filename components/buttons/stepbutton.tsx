@@ -0,0 +1,24 @@
+import SkipNextIcon from "@mui/icons-material/SkipNext";
+import {Button} from "@mui/material";
+import {useTheme} from "@mui/material/styles";
+import {useAtomValue} from "jotai";
+import {playAtom, stepAtom} from "lib/atoms/atoms";
+import {useUpdateAtom} from "jotai/utils";
+
+export const StepButton = () => {
+    const play = useAtomValue(playAtom);
+    const setStep = useUpdateAtom(stepAtom);
+
+    const theme = useTheme();
+    return (
+        <Button
+            title='Step one frame while paused <Ctrl + Alt + Right>'
+            onClick={() => setStep(true)}
+            disabled={play}
+            sx={{color: theme.palette.primary.light}}>
+                <SkipNextIcon />
+        </Button>
+    );
+}
+
+export default StepButton;
@@ -1,13 +1,15 @@
 
 import {getRainbowColor, Item} from '../../theme/theme';
 import {useAtom, useAtomValue} from "jotai";
-import {float32EnabledAtom} from "lib/atoms/atoms";
+import {fixedTimestepAtom, float32EnabledAtom} from "lib/atoms/atoms";
 import {List, ListItem, ListItemIcon, ListItemText, ListSubheader, Switch} from "@mui/material";
 import LineStyleIcon from '@mui/icons-material/LineStyle';
+import TimerIcon from '@mui/icons-material/Timer';
 import {useTheme} from "@mui/material/styles";
 
 export default function ConfigurationPicker() {
     const [float32Enabled, setFloat32Enabled] = useAtom(float32EnabledAtom);
+    const [fixedTimestep, setFixedTimestep] = useAtom(fixedTimestepAtom);
     const theme = useTheme();
     return (
         <Item sx={{
@@ -36,6 +38,20 @@ export default function ConfigurationPicker() {
                         }}
                     />
                 </ListItem>
+                <ListItem>
+                    <ListItemIcon sx={{minWidth: "32px", color: theme.palette.dracula.foreground}}>
+                        <TimerIcon />
+                    </ListItemIcon>
+                    <ListItemText id="config-list-label-fixed-timestep" primary="Fixed Timestep" />
+                    <Switch
+                        edge="end"
+                        onChange={() => {setFixedTimestep(!fixedTimestep)}}
+                        checked={fixedTimestep}
+                        inputProps={{
+                            'aria-labelledby': "config-list-label-fixed-timestep",
+                        }}
+                    />
+                </ListItem>
             </List>
         </Item>
     );
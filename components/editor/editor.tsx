@@ -12,6 +12,9 @@ import {Button, useMediaQuery, useTheme} from "@mui/material";
 
 import PlayPauseButton from "components/buttons/playpausebutton"
 import ResetButton from "components/buttons/resetbutton";
+import StepBackButton from "components/buttons/stepbackbutton";
+import StepButton from "components/buttons/stepbutton";
+import SpeedButton from "components/buttons/speedbutton";
 import HotReloadToggle from "components/buttons/hotreloadtoggle";
 import ReloadButton from "components/buttons/reloadbutton";
 import ScaleButton from 'components/buttons/scalebutton';
@@ -35,6 +38,7 @@ import dynamic from "next/dynamic";
 import { supabase } from "lib/db/supabaseclient";
 import VimButton from 'components/buttons/vimbutton';
 import RecordButton from 'components/buttons/recordbutton';
+import ScreenshotButton from 'components/buttons/screenshotbutton';
 
 export const Editor = () => {
     const setCanvasParentEl = useUpdateAtom(canvasParentElAtom);
@@ -61,7 +65,7 @@ export const Editor = () => {
     const leftPanel = (
         <div ref={renderParentNodeRef}>
             <ItemWithTransitionSignal transitionAtom={saveColorTransitionSignalAtom}>
-                <Frame elevation={12}>
+                <Frame elevation={12} sx={{position: "relative"}}>
                     <WgpuToyWrapper
                         bindID={"editor-canvas"}
                         style={{
@@ -76,8 +80,12 @@ export const Editor = () => {
                     </Grid>
                     <Grid item xs={7}>
                         <PlayPauseButton />
+                        <StepBackButton />
+                        <StepButton />
+                        <SpeedButton />
                         <ResetButton />
                         <RecordButton />
+                        <ScreenshotButton />
                     </Grid>
                     <Grid item sx={{ textAlign: 'right' }} xs={3}>
                         <Resolution />
@@ -3,7 +3,7 @@ import {useEffect, useState, useRef} from "react";
 import {wgslLanguageDef, wgslConfiguration} from 'public/grammars/wgsl'
 import {defineMonacoTheme} from "theme/monacotheme";
 import {useAtom, useAtomValue} from "jotai";
-import {codeAtom,  dbLoadedAtom,  isPlayingAtom,  manualReloadAtom,  parseErrorAtom, playAtom, resetAtom, vimAtom} from "lib/atoms/atoms";
+import {codeAtom,  dbLoadedAtom,  isPlayingAtom,  manualReloadAtom,  parseErrorAtom, playAtom, resetAtom, seekAtom, stepAtom, timerAtom, vimAtom} from "lib/atoms/atoms";
 import { useAtomCallback, useUpdateAtom } from 'jotai/utils';
 import { useTransientAtom } from 'jotai-game';
 
@@ -18,6 +18,9 @@ const Monaco = (props) => {
     const setPlay = useUpdateAtom(playAtom);
     const setManualReload = useUpdateAtom(manualReloadAtom);
     const setReset = useUpdateAtom(resetAtom);
+    const setStep = useUpdateAtom(stepAtom);
+    const setSeek = useUpdateAtom(seekAtom);
+    const [timer] = useTransientAtom(timerAtom);
     const vim = useAtomValue(vimAtom)
     const [vimContext, setVimContext] = useState(undefined)
     const [editor, setEditor] = useState(undefined)
@@ -148,6 +151,14 @@ const Monaco = (props) => {
             _editor.addCommand( monaco.KeyMod.Alt | monaco.KeyMod.CtrlCmd | monaco.KeyCode.UpArrow, () => {
                 setPlay(!isPlaying())
             })
+            // Step shortcut
+            _editor.addCommand( monaco.KeyMod.Alt | monaco.KeyMod.CtrlCmd | monaco.KeyCode.RightArrow, () => {
+                if (!isPlaying()) setStep(true)
+            })
+            // Step back shortcut
+            _editor.addCommand( monaco.KeyMod.Alt | monaco.KeyMod.CtrlCmd | monaco.KeyCode.LeftArrow, () => {
+                if (!isPlaying() && timer() > 0) setSeek(Math.max(0, timer() - 1 / 60))
+            })
             // Rewind shortcut
             _editor.addCommand( monaco.KeyMod.Alt | monaco.KeyMod.CtrlCmd | monaco.KeyCode.DownArrow, () => {
                 setReset(true)
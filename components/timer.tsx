@@ -1,13 +1,14 @@
 import { Box } from "@mui/material";
 import { useAtomValue } from "jotai";
 import { useTransientAtom } from "jotai-game";
-import { isPlayingAtom, timerAtom } from "lib/atoms/atoms";
+import { frameStatsAtom, isPlayingAtom, timerAtom } from "lib/atoms/atoms";
 import { Fragment, useRef, useState } from "react";
 import { theme } from "theme/theme";
 import useAnimationFrame from "use-animation-frame";
 
 export default function Timer() {
     const timer = useAtomValue(timerAtom);
+    const frameStats = useAtomValue(frameStatsAtom);
     const [isPlaying, setIsPlaying] = useTransientAtom(isPlayingAtom);
     const frames = useRef(0);
     const secs = useRef(0);
@@ -25,7 +26,15 @@ export default function Timer() {
         }
     });
 
-    if (timer > 0 && fps > 0) {
-        return <Fragment><Box marginTop="7px" marginLeft="1.3rem"><span style={{color: theme.palette.dracula.foreground, lineHeight: "25px", display: "inline-block"}}>{timer.toFixed(1)}s / {fps.toFixed(1)} FPS</span></Box></Fragment>
+    const ms = (seconds: number) => `${(seconds * 1000).toFixed(1)} ms`;
+    let pacing = `Frame time: ${ms(frameStats.meanInterval)} mean, ${ms(frameStats.maxInterval)} max\nJanky frames: ${frameStats.jankCount}`;
+    if (frameStats.lastJank) {
+        pacing += ` (last: ${ms(frameStats.lastJank.interval)}, ${frameStats.lastJank.culprit})`;
+    }
+
+    // shown as soon as time has advanced, including single steps while paused
+    if (timer > 0) {
+        const readout = fps > 0 ? `${timer.toFixed(1)}s / ${fps.toFixed(1)} FPS` : `${timer.toFixed(1)}s`;
+        return <Fragment><Box marginTop="7px" marginLeft="1.3rem"><span title={pacing} style={{color: theme.palette.dracula.foreground, lineHeight: "25px", display: "inline-block"}}>{readout}</span></Box></Fragment>
     }
 }
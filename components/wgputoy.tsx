@@ -5,7 +5,36 @@ import dynamic from "next/dynamic";
 import {useAtomValue} from "jotai";
 import {Skeleton} from "@mui/material";
 import {getDimensions} from "types/canvasdimensions";
-import {wgpuAvailabilityAtom} from "lib/atoms/atoms";
+import {shaderStatusAtom, wgpuAvailabilityAtom} from "lib/atoms/atoms";
+import {theme} from "theme/theme";
+
+const STATUS_LABELS = {
+    loading: 'Loading textures…',
+    compiling: 'Compiling…',
+    error: 'Compile error',
+};
+
+// shown over the top left of the canvas until the shader is ready
+const ShaderStatusOverlay = () => {
+    const status = useAtomValue(shaderStatusAtom);
+    if (status === 'ready') {
+        return null;
+    }
+    return (
+        <span style={{
+            position: "absolute",
+            top: "8px",
+            left: "8px",
+            padding: "2px 6px",
+            borderRadius: "4px",
+            pointerEvents: "none",
+            backgroundColor: theme.palette.dracula.background,
+            color: status === 'error' ? theme.palette.dracula.orange : theme.palette.dracula.foreground
+        }}>
+            {STATUS_LABELS[status]}
+        </span>
+    );
+};
 
 export const WgpuToyWrapper = (props) => {
     const setCanvasEl = useUpdateAtom(canvasElAtom);
@@ -46,7 +75,7 @@ export const WgpuToyWrapper = (props) => {
                 id={props.bindID}
                 style={loaded ? props.style : {position: "fixed", display: "hidden"}}
             />
-            {loaded ? null : <Skeleton variant="rectangular"
+            {loaded ? <ShaderStatusOverlay/> : <Skeleton variant="rectangular"
                                  width={dim.x}
                                  height={dim.y}/>}
             <Controller onLoad={onLoad}/>
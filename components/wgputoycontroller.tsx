@@ -1,4 +1,4 @@
-import {useCallback, useEffect, useState} from "react";
+import {useCallback, useEffect, useRef, useState} from "react";
 import {atom, useAtom, useAtomValue} from "jotai";
 import {
     codeAtom, dbLoadedAtom, entryPointsAtom, float32EnabledAtom, halfResolutionAtom,
@@ -15,9 +15,17 @@ import {
     widthAtom,
     heightAtom,
     pauseTimeWhileStillRenderingAtom,
-    recordingAtom
+    recordingAtom,
+    screenshotAtom,
+    fixedTimestepAtom,
+    stepAtom,
+    speedAtom,
+    seekAtom,
+    shaderStatusAtom,
+    frameStatsAtom,
+    JankCulprit
 } from "lib/atoms/atoms";
-import {useUpdateAtom} from "jotai/utils";
+import {useResetAtom, useUpdateAtom} from "jotai/utils";
 import {
     canvasElAtom,
     canvasParentElAtom,
@@ -32,6 +40,48 @@ import {theme} from "theme/theme";
 
 const needsInitialResetAtom = atom<boolean>(false);
 
+// tracks the OS/browser reduced motion setting while the controller is mounted
+const REDUCED_MOTION_QUERY = '(prefers-reduced-motion: reduce)';
+const reducedMotionAtom = atom<boolean>(false);
+
+// highest frame rate played back while reduced motion is preferred
+const REDUCED_MOTION_MAX_FPS = 10;
+
+// frames elapsed under fixed timestep; time is derived from this count
+// rather than summed from deltas, so that it cannot drift
+const fixedFrameAtom = atom<number>(0);
+
+// frames per second of simulated time when fixed timestep is enabled
+const FIXED_TIMESTEP_RATE = 60;
+
+// delay before hot reload recompiles, so rapid typing doesn't queue up pipeline builds
+const HOT_RELOAD_DEBOUNCE_MS = 250;
+
+// number of recent frame intervals used for pacing statistics
+const FRAME_STATS_WINDOW = 120;
+
+// a frame counts as janky when its interval exceeds this multiple of the
+// rolling mean, once enough frames have been seen to trust the mean
+const JANK_FACTOR = 2;
+const JANK_MIN_SAMPLES = 30;
+
+// time advanced by a single step while paused, in seconds
+const STEP_DELTA = 1 / FIXED_TIMESTEP_RATE;
+
+// save a blob through a temporary download link
+const downloadBlob = (blob: Blob, filename: string) => {
+    const url = window.URL.createObjectURL(blob);
+    let a = document.createElement("a");
+    document.body.appendChild(a);
+    // @ts-ignore
+    a.style = "display: none";
+    a.href = url;
+    a.download = filename;
+    a.click();
+    window.URL.revokeObjectURL(url);
+    a.remove();
+};
+
 /*
     Controller component. Returns null because we expect to be notified
     when a new canvas element is rendered to the DOM by a parent node
@@ -45,8 +95,10 @@ const WgpuToyController = (props) => {
     const [play, setPlay] = useAtom(playAtom);
     const [pauseTimeWhileStillRendering, setPauseTimeWhileStillRendering] = useAtom(pauseTimeWhileStillRenderingAtom);
     const [reset, setReset] = useAtom(resetAtom);
+    const [seek, setSeek] = useAtom(seekAtom);
     const hotReload = useAtomValue(hotReloadAtom);
     const [recording, setRecording] = useAtom(recordingAtom);
+    const [screenshot, setScreenshot] = useAtom(screenshotAtom);
 
     // must be transient so we can access updated value in play loop
     const [sliderUpdateSignal, setSliderUpdateSignal] = useTransientAtom(sliderUpdateSignalAtom);
@@ -58,6 +110,11 @@ const WgpuToyController = (props) => {
     const [hotReloadHot,] = useTransientAtom(hotReloadAtom);
     const [sliderRefMap,] = useTransientAtom(sliderRefMapAtom);
     const [timer, setTimer] = useTransientAtom(timerAtom);
+    const [fixedTimestepHot,] = useTransientAtom(fixedTimestepAtom);
+    const [fixedFrame, setFixedFrame] = useTransientAtom(fixedFrameAtom);
+    const [step, setStep] = useTransientAtom(stepAtom);
+    const [speed,] = useTransientAtom(speedAtom);
+    const [reducedMotion, setReducedMotion] = useTransientAtom(reducedMotionAtom);
 
     // transient atom can't be used with effect hook, and we want both
     // "hot" access and effect hook access for code
@@ -67,6 +124,9 @@ const WgpuToyController = (props) => {
     const loadedTextures = useAtomValue(loadedTexturesAtom);
     const setEntryPoints = useUpdateAtom(entryPointsAtom);
     const setSaveColorTransitionSignal = useUpdateAtom(saveColorTransitionSignalAtom);
+    const setFrameStats = useUpdateAtom(frameStatsAtom);
+    const resetFrameStats = useResetAtom(frameStatsAtom);
+    const setShaderStatus = useUpdateAtom(shaderStatusAtom);
 
     const wgputoy = useAtomValue(wgputoyAtom);
     const canvas = useAtomValue(canvasElAtom);
@@ -80,8 +140,37 @@ const WgpuToyController = (props) => {
 
     const [requestFullscreenSignal, setRequestFullscreenSignal] = useAtom(requestFullscreenAtom);
     const float32Enabled = useAtomValue(float32EnabledAtom);
+    const fixedTimestep = useAtomValue(fixedTimestepAtom);
     const halfResolution = useAtomValue(halfResolutionAtom);
 
+    // bumped on every reload request; a preprocess result is only compiled
+    // if no newer reload has been requested while it was in flight
+    const reloadGeneration = useRef(0);
+
+    // rolling window of frame intervals, and what happened since the last
+    // frame that could explain it being slow
+    const frameIntervals = useRef<number[]>([]);
+    const frameCulprit = useRef<JankCulprit>('unknown');
+    const frameStatsElapsed = useRef(0);
+    const skipFrameInterval = useRef(false);
+
+    // time held back from frames skipped by the reduced motion rate cap
+    const heldDelta = useRef(0);
+
+    // set when autoplay is suppressed on mount, so that the play effect
+    // doesn't act on the initial value of playAtom before it updates
+    const suppressAutoplay = useRef(false);
+
+    // video track of a recording started under fixed timestep, which only
+    // captures a frame when one is requested after each advanced frame
+    const captureTrack = useRef<CanvasCaptureMediaStreamTrack | null>(null);
+
+    // channel textures still being fetched and uploaded
+    const pendingTextureLoads = useRef(0);
+
+    // debounced hot reload that hasn't fired yet
+    const pendingHotReload = useRef<ReturnType<typeof setTimeout> | null>(null);
+
     const updateUniforms = useCallback(async () => {
         if (isSafeContext(wgputoy)) {
             let names: string[] = [];
@@ -97,11 +186,21 @@ const WgpuToyController = (props) => {
         }
     }, []);
 
+    // any reload supersedes a debounced hot reload that is still waiting
+    const cancelPendingHotReload = () => {
+        clearTimeout(pendingHotReload.current);
+        pendingHotReload.current = null;
+    };
+
     const reloadCallback = useCallback( () => {
+        cancelPendingHotReload();
+        setShaderStatus('compiling');
+        const generation = ++reloadGeneration.current;
         updateUniforms().then(() => {
             if (isSafeContext(wgputoy)) {
                 wgputoy.preprocess(codeHot()).then(s => {
-                    if (s) {
+                    if (s && generation === reloadGeneration.current) {
+                        frameCulprit.current = 'compile';
                         wgputoy.compile(s);
                         wgputoy.render();
                     }
@@ -113,10 +212,14 @@ const WgpuToyController = (props) => {
     }, []);
 
     const awaitableReloadCallback = async () => {
+        cancelPendingHotReload();
+        setShaderStatus('compiling');
+        const generation = ++reloadGeneration.current;
         return updateUniforms().then(() => {
             if (isSafeContext(wgputoy)) {
                 wgputoy.preprocess(codeHot()).then(s => {
-                    if (s) {
+                    if (s && generation === reloadGeneration.current) {
+                        frameCulprit.current = 'compile';
                         wgputoy.compile(s);
                         wgputoy.render();
                     }
@@ -149,8 +252,81 @@ const WgpuToyController = (props) => {
     }, [])
 
 
+    // advance time by one frame and render it; fixed timestep ignores delta
+    const advanceFrame = useCallback((delta: number) => {
+        if (isSafeContext(wgputoy)) {
+            let t = timer();
+            if (fixedTimestepHot()) {
+                const frame = fixedFrame() + 1;
+                setFixedFrame(frame);
+                t = frame / FIXED_TIMESTEP_RATE;
+                delta = 1 / FIXED_TIMESTEP_RATE;
+            } else {
+                t += delta;
+            }
+            setTimer(t);
+            wgputoy.set_time_elapsed(t);
+            wgputoy.set_time_delta(delta);
+            wgputoy.render();
+            if (captureTrack.current) {
+                captureTrack.current.requestFrame();
+            }
+        }
+    }, []);
+
+    // record a frame interval, publishing rolling statistics twice a
+    // second and immediately when a janky frame is detected
+    const updateFrameStats = (delta: number) => {
+        const intervals = frameIntervals.current;
+        const mean = intervals.reduce((a, b) => a + b, 0) / intervals.length;
+        const isJank = intervals.length >= JANK_MIN_SAMPLES && delta > JANK_FACTOR * mean;
+        intervals.push(delta);
+        if (intervals.length > FRAME_STATS_WINDOW) {
+            intervals.shift();
+        }
+        frameStatsElapsed.current += delta;
+        if (isJank || frameStatsElapsed.current > .5) {
+            frameStatsElapsed.current = 0;
+            setFrameStats(stats => ({
+                meanInterval: intervals.reduce((a, b) => a + b, 0) / intervals.length,
+                maxInterval: Math.max(...intervals),
+                jankCount: stats.jankCount + (isJank ? 1 : 0),
+                lastJank: isJank ? {interval: delta, culprit: frameCulprit.current} : stats.lastJank
+            }));
+        }
+    };
+
+    const clearFrameStats = () => {
+        frameIntervals.current = [];
+        frameStatsElapsed.current = 0;
+        resetFrameStats();
+    };
+
+    /*
+        Browsers pause animation frames in hidden tabs, so the first interval
+        after the tab comes back spans the whole time away; start the window
+        afresh rather than counting that as jank
+     */
+    useEffect(() => {
+        const handleVisibilityChange = () => {
+            frameIntervals.current = [];
+            frameStatsElapsed.current = 0;
+            skipFrameInterval.current = true;
+        };
+        document.addEventListener('visibilitychange', handleVisibilityChange);
+        return () => document.removeEventListener('visibilitychange', handleVisibilityChange);
+    }, []);
+
     useAnimationFrame(e => {
         if (isSafeContext(wgputoy)) {
+            // compiles and uploads happen between frames, so they show up
+            // in the interval measured at the start of the next one
+            if (skipFrameInterval.current) {
+                skipFrameInterval.current = false;
+            } else if (isPlaying() && !document.hidden) {
+                updateFrameStats(e.delta);
+            }
+            frameCulprit.current = 'unknown';
             if (sliderUpdateSignal()) {
                 updateUniforms().then(() => {
                     liveReloadCallback();
@@ -158,16 +334,31 @@ const WgpuToyController = (props) => {
             } else {
                 liveReloadCallback();
             }
-            if(pauseTimeWhileStillRendering || (sliderUpdateSignal() && !isPlaying())){
+            if (step() && !isPlaying()) {
+                // advance exactly one frame with a fixed delta
+                advanceFrame(STEP_DELTA);
+                setStep(false);
+            } else if(pauseTimeWhileStillRendering || (sliderUpdateSignal() && !isPlaying())){
                 wgputoy.set_time_delta(e.delta);
                 wgputoy.render();
             } else if (isPlaying() || manualReload()) {
-                let t = timer();
-                t += e.delta;
-                setTimer(t);
-                wgputoy.set_time_elapsed(t);
-                wgputoy.set_time_delta(e.delta);
-                wgputoy.render();
+                // under reduced motion, skip renders until enough time has
+                // passed for the capped frame rate
+                const delta = heldDelta.current + e.delta;
+                if (reducedMotion() && !manualReload() && delta < 1 / REDUCED_MOTION_MAX_FPS) {
+                    heldDelta.current = delta;
+                } else if (reducedMotion() && fixedTimestepHot()) {
+                    // catch up on the fixed timestep frames covered by the
+                    // held time, so capped playback still runs in real time
+                    heldDelta.current = 0;
+                    const frames = Math.max(1, Math.round(delta * FIXED_TIMESTEP_RATE));
+                    for (let f = 0; f < frames; f++) {
+                        advanceFrame(STEP_DELTA);
+                    }
+                } else {
+                    heldDelta.current = 0;
+                    advanceFrame(delta * speed());
+                }
             }
         }
     });
@@ -185,13 +376,46 @@ const WgpuToyController = (props) => {
             const dimensions = getDimensions(parentRef.offsetWidth); //theoretically dangerous call?
             setWidth(dimensions.x);
             setTimer(0);
+            setFixedFrame(0);
+            clearFrameStats();
             wgputoy.reset();
             reloadCallback();
         }
     }, []);
 
+    /*
+        Fast-forward to a frame by resetting and replaying every frame up to
+        it on the fixed timestep clock, all within one task and without
+        waiting for animation frames. A deterministic shader ends up in the
+        same state as if it had played to that frame with fixed timestep on.
+     */
+    const seekCallback = useCallback((frame: number) => {
+        cancelPendingHotReload();
+        setShaderStatus('compiling');
+        const generation = ++reloadGeneration.current;
+        updateUniforms().then(() => {
+            if (isSafeContext(wgputoy)) {
+                wgputoy.reset();
+                wgputoy.preprocess(codeHot()).then(s => {
+                    if (s && generation === reloadGeneration.current) {
+                        frameCulprit.current = 'seek';
+                        wgputoy.compile(s);
+                        for (let f = 0; f <= frame; f++) {
+                            wgputoy.set_time_elapsed(f / FIXED_TIMESTEP_RATE);
+                            wgputoy.set_time_delta(1 / FIXED_TIMESTEP_RATE);
+                            wgputoy.render();
+                        }
+                        setFixedFrame(frame);
+                        setTimer(frame / FIXED_TIMESTEP_RATE);
+                    }
+                });
+            }
+        });
+    }, []);
+
     const handleSuccess = useCallback((entryPoints) => {
         setEntryPoints(entryPoints);
+        setShaderStatus('ready');
         setParseError(error => ({
             summary: "",
             position: {row: 0, col: 0},
@@ -201,6 +425,7 @@ const WgpuToyController = (props) => {
     }, []);
 
     const handleError = useCallback((summary: string, row: number, col: number) => {
+        setShaderStatus('error');
         setParseError(error => ({
             summary: summary,
             position: {row: Number(row), col: Number(col)},
@@ -213,6 +438,9 @@ const WgpuToyController = (props) => {
 
     const loadTexture = useCallback((index: number, uri: string) => {
         if (isSafeContext(wgputoy)) {
+            // only shown as loading while the shader would otherwise be ready
+            pendingTextureLoads.current += 1;
+            setShaderStatus(status => status === 'ready' ? 'loading' : status);
             fetch(uri).then(
                 response => {
                     if (!response.ok) {
@@ -221,13 +449,19 @@ const WgpuToyController = (props) => {
                     return response.blob();
                 }).then(b => b.arrayBuffer()).then(
                 data => {
+                    frameCulprit.current = 'upload';
                     if (uri.match(/\.hdr/i)) {
                         wgputoy.load_channel_hdr(index, new Uint8Array(data))
                     } else {
                         wgputoy.load_channel(index, new Uint8Array(data))
                     }
                 }
-            ).catch(error => console.error(error));
+            ).catch(error => console.error(error)).then(() => {
+                pendingTextureLoads.current -= 1;
+                if (pendingTextureLoads.current === 0) {
+                    setShaderStatus(status => status === 'loading' ? 'ready' : status);
+                }
+            });
         }
     }, []);
 
@@ -271,7 +505,7 @@ const WgpuToyController = (props) => {
         if(!canvas){ 
             return;
         }
-        function createMediaRecorder(canvas: HTMLCanvasElement){
+        function createMediaRecorder(canvas: HTMLCanvasElement, fixedTimestep: boolean){
             let options: any = { audioBitsPerSecond : 0, videoBitsPerSecond : 8000000 }; 
 
             const types = ['video/webm;codecs=h264', 'video/webm;codecs=vp9', 'video/webm;codecs=vp8'];
@@ -285,7 +519,11 @@ const WgpuToyController = (props) => {
                 options.mimeType = 'video/webm'
             }
 
-            const mediaRecorder = new MediaRecorder(canvas.captureStream(), options);
+            // with fixed timestep, record exactly one video frame per simulated
+            // frame instead of sampling the canvas on the wall clock
+            const stream = canvas.captureStream(fixedTimestep ? 0 : undefined);
+            captureTrack.current = fixedTimestep ? stream.getVideoTracks()[0] as CanvasCaptureMediaStreamTrack : null;
+            const mediaRecorder = new MediaRecorder(stream, options);
             const chunks = [];
             
             mediaRecorder.ondataavailable = function(e) {
@@ -295,18 +533,11 @@ const WgpuToyController = (props) => {
             };
          
             mediaRecorder.onstop = function() {
+                captureTrack.current = null;
                 setRecording(false)
                 let blob     = new Blob(chunks, {type: "video/mp4"});
                 chunks.length = 0;
-                const url      = window.URL.createObjectURL(blob);
-                let a        = document.createElement("a");
-                document.body.appendChild(a);
-                // @ts-ignore
-                a.style      = "display: none";
-                a.href       = url;
-                a.download   = "shader.mp4";
-                a.click();
-                window.URL.revokeObjectURL(url);
+                downloadBlob(blob, "shader.mp4");
              };
 
             // @ts-ignore
@@ -317,11 +548,9 @@ const WgpuToyController = (props) => {
         // @ts-ignore
         let mediaRecorder: MediaRecorder = window.mediaRecorder
         if(recording){
-            if(!mediaRecorder){
-                mediaRecorder = createMediaRecorder(canvas)
-            }
-            
-            if (mediaRecorder.state === "inactive") {
+            // a new recorder each time, so it picks up the current timestep mode
+            if(!mediaRecorder || mediaRecorder.state === "inactive"){
+                mediaRecorder = createMediaRecorder(canvas, fixedTimestepHot())
                 mediaRecorder.start();
             }                 
         } else if (!recording && mediaRecorder) {
@@ -331,6 +560,22 @@ const WgpuToyController = (props) => {
         }
     }, [recording])
 
+    useEffect(() => {
+        /*
+            the canvas only holds the rendered image until the end of the
+            current task, so render and snapshot it together
+        */
+        if (screenshot) {
+            if (isSafeContext(wgputoy) && canvas !== false) {
+                wgputoy.render();
+                canvas.toBlob(blob => {
+                    if (blob) downloadBlob(blob, "shader.png");
+                }, "image/png");
+            }
+            setScreenshot(false);
+        }
+    }, [screenshot])
+
     useEffect(() => {
         if (canvas !== false) {
             const handleMouseMove = (e: MouseEvent) => {
@@ -373,9 +618,16 @@ const WgpuToyController = (props) => {
 
     useEffect(() => {
         if (!isPlaying()) {
-            setPlay(true);
             setNeedsInitialReset(true);
-            playCallback();
+            // honour the OS/browser reduced motion setting by rendering
+            // the first frame but not starting playback automatically
+            if (window.matchMedia(REDUCED_MOTION_QUERY).matches) {
+                suppressAutoplay.current = true;
+                setPlay(false);
+            } else {
+                setPlay(true);
+                playCallback();
+            }
         }
     }, []);
 
@@ -383,6 +635,18 @@ const WgpuToyController = (props) => {
     useEffect(() => pauseCallback, []);
 
     useEffect(() => {
+        const query = window.matchMedia(REDUCED_MOTION_QUERY);
+        const handleChange = () => setReducedMotion(query.matches);
+        handleChange();
+        query.addEventListener('change', handleChange);
+        return () => query.removeEventListener('change', handleChange);
+    }, []);
+
+    useEffect(() => {
+        if (suppressAutoplay.current) {
+            suppressAutoplay.current = false;
+            return;
+        }
         if (play && !isPlaying()) {
             playCallback();
             setPauseTimeWhileStillRendering(false)
@@ -391,15 +655,32 @@ const WgpuToyController = (props) => {
         }
     }, [play, isPlaying()])
 
+    useEffect(() => {
+        /*
+            hot reload is debounced so that each keystroke doesn't trigger
+            a recompile; an edit still pending when hot reload is switched
+            off is flushed rather than dropped
+        */
+        if (hotReload) {
+            clearTimeout(pendingHotReload.current);
+            pendingHotReload.current = setTimeout(reloadCallback, HOT_RELOAD_DEBOUNCE_MS);
+        } else if (pendingHotReload.current !== null) {
+            reloadCallback();
+        }
+    }, [code, hotReload]);
+
     useEffect(() => {
         /*
             only need to handle manual reload effect here for
             special case where we're paused and a reload is called
         */
-        if ((hotReload || (!isPlaying() && manualReload()))) {
+        if (!isPlaying() && manualReload()) {
             reloadCallback();
         }
-    }, [code, hotReload, manualReload()]);
+    }, [manualReload()]);
+
+    // drop a pending hot reload on unmount
+    useEffect(() => () => clearTimeout(pendingHotReload.current), []);
 
     const updateResolution = () => {
         if (isSafeContext(wgputoy)) {
@@ -440,6 +721,13 @@ const WgpuToyController = (props) => {
         }
     }, [reset]);
 
+    useEffect(() => {
+        if (seek !== false) {
+            seekCallback(Math.max(0, Math.round(seek * FIXED_TIMESTEP_RATE)));
+            setSeek(false);
+        }
+    }, [seek]);
+
     useEffect(() => {
         loadTexture(0, loadedTextures[0].img);
     }, [loadedTextures[0]]);
@@ -448,6 +736,13 @@ const WgpuToyController = (props) => {
         loadTexture(1, loadedTextures[1].img);
     }, [loadedTextures[1]]);
 
+    // continue from the current time when fixed timestep is switched on
+    useEffect(() => {
+        if (fixedTimestep) {
+            setFixedFrame(Math.round(timer() * FIXED_TIMESTEP_RATE));
+        }
+    }, [fixedTimestep]);
+
     useEffect(() => {
         if (requestFullscreenSignal) {
             requestFullscreen();
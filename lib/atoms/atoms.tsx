@@ -45,13 +45,31 @@ export interface Texture {
 
 export type Visibility = 'private' | 'unlisted' | 'public';
 
+// most likely cause of a slow frame, as far as the frontend can tell
+export type JankCulprit = 'compile' | 'upload' | 'seek' | 'unknown';
+
+export interface FrameStats {
+    meanInterval: number, // seconds, over the rolling window
+    maxInterval: number,
+    jankCount: number,
+    lastJank: {interval: number, culprit: JankCulprit} | null
+}
+
 export type WgpuStatus = 'available' | 'unavailable' | 'unknown';
 
+// where the current shader is in its load lifecycle
+export type ShaderStatus = 'loading' | 'compiling' | 'ready' | 'error';
+
 export const wgpuAvailabilityAtom = atom<WgpuStatus>('unknown');
+export const shaderStatusAtom = atom<ShaderStatus>('loading');
 
 export const playAtom = atom<boolean>(true);
 export const pauseTimeWhileStillRenderingAtom = atom<boolean>(false);
 export const resetAtom = atom<boolean>(false);
+export const stepAtom = atom<boolean>(false);
+export const speedAtom = atom<number>(1);
+// time in seconds to seek to by resetting and replaying frames
+export const seekAtom = atom<number | false>(false);
 export const hotReloadAtom = atom<boolean>(false);
 export const manualReloadAtom = atom<boolean>(false);
 export const requestFullscreenAtom = atom<boolean>(false);
@@ -63,8 +81,11 @@ export const parseErrorAtom = atom<ParseError>({
 export const isPlayingAtom = atom<boolean>(false);
 export const vimAtom = atom<boolean>(false);
 export const recordingAtom = atom<boolean>(false);
+export const screenshotAtom = atom<boolean>(false);
+export const fixedTimestepAtom = atom<boolean>(false);
 
 export const timerAtom = atom<number>(0);
+export const frameStatsAtom = atomWithReset<FrameStats>({meanInterval: 0, maxInterval: 0, jankCount: 0, lastJank: null});
 export const widthAtom = atom<number>(0);
 export const heightAtom = atom<number>(0);
 export const scaleAtom = atom<number>(1);